@@ -0,0 +1,64 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+// This file is part of Substrate.
+
+// Substrate is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Substrate is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Substrate.  If not, see <http://www.gnu.org/licenses/>.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+/// The base path that is used for everything that needs to be written on disk to run a node.
+#[derive(Debug)]
+pub enum BasePath {
+	/// A path that is created in `OS`'s temporary directory and deleted when the node exits.
+	///
+	/// The directory is kept alive for as long as this value lives, so it must be stored
+	/// somewhere that outlives the service built from it (e.g. on the `Configuration`).
+	Temporary(TempDir),
+	/// A path on the disk that was explicitly provided by the user and is kept as is.
+	Permanent(PathBuf),
+}
+
+impl BasePath {
+	/// Create a [`BasePath`] instance using a temporary directory prefixed with "substrate" and
+	/// randomly created in `std::env::temp_dir()`.
+	pub fn new_temp_dir() -> std::io::Result<BasePath> {
+		Ok(BasePath::Temporary(tempfile::Builder::new().prefix("substrate").tempdir()?))
+	}
+
+	/// Create a [`BasePath`] instance based on an existing path on disk.
+	pub fn new<P: Into<PathBuf>>(path: P) -> BasePath {
+		BasePath::Permanent(path.into())
+	}
+
+	/// Retrieve the base path.
+	pub fn path(&self) -> &Path {
+		match self {
+			BasePath::Temporary(temp_dir) => temp_dir.path(),
+			BasePath::Permanent(path) => path.as_path(),
+		}
+	}
+}
+
+impl fmt::Display for BasePath {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}", self.path().display())
+	}
+}
+
+impl From<PathBuf> for BasePath {
+	fn from(path: PathBuf) -> Self {
+		BasePath::new(path)
+	}
+}
@@ -0,0 +1,109 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+// This file is part of Substrate.
+
+// Substrate is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Substrate is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Substrate.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::commands::SharedParams;
+use crate::error;
+use crate::CliConfiguration;
+use crate::Result;
+use log::info;
+use sc_client_api::{backend::Backend, StorageProvider, UsageProvider};
+use sc_service::config::{Configuration, DatabaseConfig};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::generic::BlockId;
+use sp_runtime::traits::Block as BlockT;
+use std::fmt::Debug;
+use std::io::Write;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use structopt::StructOpt;
+
+/// The `export-state` command used to export the state of a given block into
+/// a chain spec.
+#[derive(Debug, Clone, StructOpt)]
+pub struct ExportStateCmd {
+	/// Block hash or number.
+	#[structopt(value_name = "BLOCK")]
+	pub input: Option<String>,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub shared_params: SharedParams,
+}
+
+impl ExportStateCmd {
+	/// Run the export-state command
+	pub async fn run<B, BE, C>(
+		&self,
+		client: Arc<C>,
+		config: Configuration,
+	) -> error::Result<()>
+	where
+		B: BlockT,
+		B::Hash: FromStr,
+		BE: Backend<B>,
+		C: HeaderBackend<B> + StorageProvider<B, BE> + UsageProvider<B>,
+	{
+		let block_id = self.block_id::<B>()?;
+
+		info!("Exporting raw state at block {}", match block_id {
+			Some(id) => format!("{}", id),
+			None => "best".into(),
+		});
+
+		let block_id = block_id.unwrap_or_else(|| BlockId::Hash(client.info().best_hash));
+
+		let raw_state = sc_service::chain_ops::export_raw_state(client, block_id)?;
+
+		let mut chain_spec = config.chain_spec.cloned_box();
+		chain_spec.set_storage(raw_state);
+
+		let json = sc_service::chain_ops::build_spec(&*chain_spec, true)?;
+
+		if std::io::stdout().write_all(json.as_bytes()).is_err() {
+			let _ = std::io::stderr().write_all(b"Error writing to stdout\n");
+		}
+
+		Ok(())
+	}
+
+	/// Parse the CLI's block hash or number argument into a [`BlockId`], if any was given.
+	fn block_id<B: BlockT>(&self) -> error::Result<Option<BlockId<B>>>
+	where
+		B::Hash: FromStr,
+	{
+		self.input
+			.as_ref()
+			.map(|input| {
+				input
+					.parse::<B::Hash>()
+					.map(BlockId::Hash)
+					.or_else(|_| input.parse::<u32>().map(|n| BlockId::Number(n.into())))
+					.map_err(|_| error::Error::Input("Invalid block hash or number provided".into()))
+			})
+			.transpose()
+	}
+}
+
+impl CliConfiguration for ExportStateCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn database_config(&self, base_path: &PathBuf, cache_size: Option<usize>) -> Result<DatabaseConfig> {
+		Ok(DatabaseConfig::RocksDb { path: base_path.join("db"), cache_size: cache_size.unwrap_or(128) })
+	}
+}
@@ -14,18 +14,24 @@
 // You should have received a copy of the GNU General Public License
 // along with Substrate.  If not, see <http://www.gnu.org/licenses/>.
 
+mod base_path;
 mod build_spec_cmd;
 mod check_block_cmd;
 mod export_blocks_cmd;
+mod export_state_cmd;
 mod import_blocks_cmd;
+mod params;
 mod purge_chain_cmd;
 mod revert_cmd;
 mod runcmd;
 
+pub use crate::commands::base_path::BasePath;
 pub use crate::commands::build_spec_cmd::BuildSpecCmd;
 pub use crate::commands::check_block_cmd::CheckBlockCmd;
 pub use crate::commands::export_blocks_cmd::ExportBlocksCmd;
+pub use crate::commands::export_state_cmd::ExportStateCmd;
 pub use crate::commands::import_blocks_cmd::ImportBlocksCmd;
+pub use crate::commands::params::SharedParams;
 pub use crate::commands::purge_chain_cmd::PurgeChainCmd;
 pub use crate::commands::revert_cmd::RevertCmd;
 pub use crate::commands::runcmd::RunCmd;
@@ -58,6 +64,9 @@ pub enum Subcommand {
 	/// Import blocks from file.
 	ImportBlocks(ImportBlocksCmd),
 
+	/// Export the state of a given block into a chain spec.
+	ExportState(ExportStateCmd),
+
 	/// Validate a single block.
 	CheckBlock(CheckBlockCmd),
 
@@ -75,6 +84,7 @@ macro_rules! match_and_call {
 				Subcommand::BuildSpec(cmd) => cmd.$method($($arg),*),
 				Subcommand::ExportBlocks(cmd) => cmd.$method($($arg),*),
 				Subcommand::ImportBlocks(cmd) => cmd.$method($($arg),*),
+				Subcommand::ExportState(cmd) => cmd.$method($($arg),*),
 				Subcommand::CheckBlock(cmd) => cmd.$method($($arg),*),
 				Subcommand::Revert(cmd) => cmd.$method($($arg),*),
 				Subcommand::PurgeChain(cmd) => cmd.$method($($arg),*),
@@ -88,6 +98,7 @@ macro_rules! match_and_call {
 				Subcommand::BuildSpec(cmd) => cmd.$method::<C>($($arg),*),
 				Subcommand::ExportBlocks(cmd) => cmd.$method::<C>($($arg),*),
 				Subcommand::ImportBlocks(cmd) => cmd.$method::<C>($($arg),*),
+				Subcommand::ExportState(cmd) => cmd.$method::<C>($($arg),*),
 				Subcommand::CheckBlock(cmd) => cmd.$method::<C>($($arg),*),
 				Subcommand::Revert(cmd) => cmd.$method::<C>($($arg),*),
 				Subcommand::PurgeChain(cmd) => cmd.$method::<C>($($arg),*),
@@ -97,7 +108,9 @@ macro_rules! match_and_call {
 }
 
 impl CliConfiguration for Subcommand {
-	match_and_call! { fn base_path(&self) -> Result<Option<&PathBuf>> }
+	match_and_call! { fn shared_params(&self) -> &SharedParams }
+
+	match_and_call! { fn base_path(&self) -> Result<Option<BasePath>> }
 
 	match_and_call! { fn is_dev(&self) -> Result<bool> }
 
@@ -0,0 +1,171 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+// This file is part of Substrate.
+
+// Substrate is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Substrate is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Substrate.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::error::Error;
+use crate::Result;
+use sc_client_api::execution_extensions::{ExecutionStrategies, ExecutionStrategy};
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// The subset of CLI parameters that can also be supplied through a `--config` TOML file.
+///
+/// Every field is optional: a field left out of the file simply means "let the command-line
+/// default (or flag) decide". `CliConfiguration`'s default accessors consult this through
+/// [`SharedParams::config_file`](super::SharedParams::config_file), which documents the
+/// precedence between this file and a CLI flag.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ConfigurationFile {
+	/// See [`CliConfiguration::pruning`](crate::CliConfiguration::pruning).
+	pub pruning: Option<String>,
+	/// See [`CliConfiguration::wasm_method`](crate::CliConfiguration::wasm_method).
+	pub wasm_method: Option<String>,
+	/// See [`CliConfiguration::execution_strategies`](crate::CliConfiguration::execution_strategies).
+	pub execution_strategies: Option<ExecutionStrategiesFile>,
+	/// See [`CliConfiguration::database_cache_size`](crate::CliConfiguration::database_cache_size).
+	pub database_cache_size: Option<usize>,
+	/// See [`CliConfiguration::node_key`](crate::CliConfiguration::node_key).
+	pub node_key: Option<String>,
+}
+
+/// The `[execution_strategies]` table of a [`ConfigurationFile`].
+///
+/// Mirrors [`ExecutionStrategies`], one field per execution phase, each an optional strategy
+/// name (`"native"`, `"wasm"`, `"both"` or `"native-else-wasm"`) since the real struct has no
+/// single scalar a TOML value could hold.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ExecutionStrategiesFile {
+	/// See [`ExecutionStrategies::syncing`].
+	pub syncing: Option<String>,
+	/// See [`ExecutionStrategies::importing`].
+	pub importing: Option<String>,
+	/// See [`ExecutionStrategies::block_construction`].
+	pub block_construction: Option<String>,
+	/// See [`ExecutionStrategies::offchain_worker`].
+	pub offchain_worker: Option<String>,
+	/// See [`ExecutionStrategies::other`].
+	pub other: Option<String>,
+}
+
+impl ExecutionStrategiesFile {
+	/// Apply this file's overrides on top of `default`, field by field.
+	pub fn merge(self, default: ExecutionStrategies) -> Result<ExecutionStrategies> {
+		Ok(ExecutionStrategies {
+			syncing: merge_strategy(self.syncing, default.syncing)?,
+			importing: merge_strategy(self.importing, default.importing)?,
+			block_construction: merge_strategy(self.block_construction, default.block_construction)?,
+			offchain_worker: merge_strategy(self.offchain_worker, default.offchain_worker)?,
+			other: merge_strategy(self.other, default.other)?,
+		})
+	}
+}
+
+fn merge_strategy(file_value: Option<String>, default: ExecutionStrategy) -> Result<ExecutionStrategy> {
+	match file_value {
+		Some(s) => ExecutionStrategy::from_str(&s)
+			.map_err(|_| Error::Input(format!("Invalid execution strategy in config file: {}", s))),
+		None => Ok(default),
+	}
+}
+
+impl ConfigurationFile {
+	/// Parse a `ConfigurationFile` out of the TOML file at `path`.
+	pub fn from_path(path: &Path) -> Result<Self> {
+		let contents = fs::read_to_string(path)?;
+		Ok(toml::from_str(&contents)?)
+	}
+
+	/// Resolve a value that can come from either the command line or the config file.
+	///
+	/// See [`SharedParams::config_file`](super::SharedParams::config_file) for the precedence
+	/// rule this implements.
+	pub fn resolve<T>(cli_value: Option<T>, file_value: Option<T>) -> Option<T> {
+		cli_value.or(file_value)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::commands::SharedParams;
+	use crate::CliConfiguration;
+	use sc_service::config::DatabaseConfig;
+	use std::io::Write;
+	use std::path::PathBuf;
+
+	#[test]
+	fn cli_value_overrides_file_value() {
+		assert_eq!(ConfigurationFile::resolve(Some("cli"), Some("file")), Some("cli"));
+	}
+
+	#[test]
+	fn file_value_is_honored_when_cli_value_absent() {
+		assert_eq!(ConfigurationFile::resolve(None, Some("file")), Some("file"));
+	}
+
+	#[test]
+	fn absent_everywhere_resolves_to_none() {
+		assert_eq!(ConfigurationFile::resolve::<&str>(None, None), None);
+	}
+
+	/// Bare-bones command that only carries `SharedParams`, used to exercise the
+	/// `CliConfiguration` defaults end-to-end against a real `--config` file.
+	struct TestCmd {
+		shared_params: SharedParams,
+	}
+
+	impl CliConfiguration for TestCmd {
+		fn shared_params(&self) -> &SharedParams {
+			&self.shared_params
+		}
+
+		fn database_config(&self, base_path: &PathBuf, cache_size: Option<usize>) -> Result<DatabaseConfig> {
+			Ok(DatabaseConfig::RocksDb { path: base_path.join("db"), cache_size: cache_size.unwrap_or(128) })
+		}
+	}
+
+	fn shared_params(config: Option<PathBuf>, database_cache_size: Option<usize>) -> SharedParams {
+		SharedParams {
+			chain: None,
+			dev: false,
+			base_path: None,
+			tmp: false,
+			config,
+			database_cache_size,
+		}
+	}
+
+	#[test]
+	fn database_cache_size_is_read_from_config_file() {
+		let mut file = tempfile::NamedTempFile::new().unwrap();
+		write!(file, "database_cache_size = 999").unwrap();
+
+		let cmd = TestCmd { shared_params: shared_params(Some(file.path().to_path_buf()), None) };
+
+		assert_eq!(cmd.database_cache_size().unwrap(), Some(999));
+	}
+
+	#[test]
+	fn database_cache_size_cli_flag_overrides_config_file() {
+		let mut file = tempfile::NamedTempFile::new().unwrap();
+		write!(file, "database_cache_size = 999").unwrap();
+
+		let cmd = TestCmd { shared_params: shared_params(Some(file.path().to_path_buf()), Some(42)) };
+
+		assert_eq!(cmd.database_cache_size().unwrap(), Some(42));
+	}
+}
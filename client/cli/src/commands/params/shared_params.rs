@@ -0,0 +1,99 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+// This file is part of Substrate.
+
+// Substrate is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Substrate is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Substrate.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::commands::params::ConfigurationFile;
+use crate::commands::BasePath;
+use crate::Result;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// Shared parameters used by all `CliConfiguration` implementors.
+#[derive(Debug, Clone, StructOpt)]
+pub struct SharedParams {
+	/// Specify the chain specification.
+	#[structopt(long, value_name = "CHAIN_SPEC")]
+	pub chain: Option<String>,
+
+	/// Specify the development chain.
+	#[structopt(long, conflicts_with_all = &["chain"])]
+	pub dev: bool,
+
+	/// Specify custom base path.
+	#[structopt(long, short = "d", value_name = "PATH", parse(from_os_str), conflicts_with_all = &["tmp"])]
+	pub base_path: Option<PathBuf>,
+
+	/// Run a temporary node.
+	///
+	/// A temporary directory will be created to store the configuration and will be deleted
+	/// at the end of the process.
+	///
+	/// Note: the directory is random per process execution. This directory is used as base path
+	/// which includes: database, node key and keystore.
+	#[structopt(long, conflicts_with_all = &["base-path"])]
+	pub tmp: bool,
+
+	/// Load CLI parameters from a TOML configuration file.
+	///
+	/// See [`Self::config_file`] for the precedence rule between this file and the other flags
+	/// on this struct.
+	#[structopt(long, value_name = "FILE", parse(from_os_str))]
+	pub config: Option<PathBuf>,
+
+	/// Limit the memory the database cache uses.
+	#[structopt(long, value_name = "MiB")]
+	pub database_cache_size: Option<usize>,
+}
+
+impl SharedParams {
+	/// Specify the chain specification.
+	pub fn chain_id(&self, is_dev: bool) -> String {
+		match self.chain {
+			Some(ref chain) => chain.clone(),
+			None => if is_dev { "dev".into() } else { "".into() },
+		}
+	}
+
+	/// Specify the development chain.
+	pub fn is_dev(&self) -> bool {
+		self.dev
+	}
+
+	/// Get the base path of the configuration.
+	///
+	/// A temporary directory is created and returned when `--tmp` was given, the directory and
+	/// all chain data inside it is removed when it is dropped, so the resulting [`BasePath`] must
+	/// be kept alive for as long as the node runs.
+	pub fn base_path(&self) -> Result<Option<BasePath>> {
+		if self.tmp {
+			Ok(Some(BasePath::new_temp_dir()?))
+		} else {
+			match self.base_path {
+				Some(ref r) => Ok(Some(BasePath::new(r.clone()))),
+				None => Ok(None),
+			}
+		}
+	}
+
+	/// Load the `--config` file, if one was given.
+	///
+	/// `CliConfiguration` accessors that want to honour `--config` should call this, then use
+	/// [`ConfigurationFile::resolve`] to let their own CLI flag win over the file's value: a flag
+	/// explicitly passed on the command line always takes precedence over the same parameter
+	/// read from this file, which only fills in the parameters that were left at their default.
+	pub fn config_file(&self) -> Result<Option<ConfigurationFile>> {
+		self.config.as_deref().map(ConfigurationFile::from_path).transpose()
+	}
+}
@@ -0,0 +1,148 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+// This file is part of Substrate.
+
+// Substrate is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Substrate is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Substrate.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Configuration trait implemented by every command-line parameter struct (and by [`Subcommand`]
+//! itself, by delegating to whichever variant was invoked), so the node service can be built
+//! generically over whatever was passed on the command line.
+//!
+//! [`Subcommand`]: crate::commands::Subcommand
+
+use crate::commands::params::ConfigurationFile;
+use crate::commands::BasePath;
+use crate::commands::SharedParams;
+use crate::Result;
+use sc_client_api::execution_extensions::ExecutionStrategies;
+use sc_network::config::identity::ed25519;
+use sc_network::config::{NodeKeyConfig, Secret};
+use sc_service::{config::DatabaseConfig, config::WasmExecutionMethod, PruningMode, Roles};
+use sc_tracing::TracingReceiver;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Default configuration trait. Every command-line parameter struct that can be turned into a
+/// running node (or used standalone as a subcommand) implements this trait.
+///
+/// `shared_params` and `database_config` are the only methods without a default. The other
+/// accessors either read the `SharedParams` that `shared_params` returns, some of them layering
+/// in a `--config` file (see [`SharedParams::config_file`]) before falling back to a hard-coded
+/// default, or (`tracing_receiver`, `tracing_targets`, `state_cache_size`) return a hard-coded
+/// default without consulting either. `database_config` has no sensible default because it is
+/// entirely derived from the base path and cache size the caller already resolved.
+pub trait CliConfiguration {
+	/// Shared parameters used by all commands.
+	fn shared_params(&self) -> &SharedParams;
+
+	/// Get the base path of the configuration.
+	///
+	/// Returns `None` if no explicit base path was provided and the implementor does not have a
+	/// sensible default, in which case a platform-specific default is used by the caller. When
+	/// `--tmp` was given on the command line the returned [`BasePath`] owns a freshly created
+	/// temporary directory; the caller must keep it alive for as long as the node runs, since it
+	/// is deleted together with the chain data it contains when the value is dropped.
+	fn base_path(&self) -> Result<Option<BasePath>> {
+		self.shared_params().base_path()
+	}
+
+	/// Returns `true` if the node is for development or not.
+	fn is_dev(&self) -> Result<bool> {
+		Ok(self.shared_params().is_dev())
+	}
+
+	/// Get the database configuration object for the parameters provided.
+	fn database_config(&self, base_path: &PathBuf, cache_size: Option<usize>) -> Result<DatabaseConfig>;
+
+	/// Get the chain ID (string).
+	fn chain_id(&self, is_dev: bool) -> Result<String> {
+		Ok(self.shared_params().chain_id(is_dev))
+	}
+
+	/// Get the pruning mode.
+	fn pruning(&self, is_dev: bool, roles: Roles) -> Result<PruningMode> {
+		let file_value = self.shared_params().config_file()?.and_then(|f| f.pruning);
+		match ConfigurationFile::resolve(None, file_value) {
+			Some(s) => PruningMode::from_str(&s)
+				.map_err(|e| crate::error::Error::Input(format!("Invalid pruning mode in config file: {}", e))),
+			None if is_dev => Ok(PruningMode::ArchiveAll),
+			None if roles.is_authority() => Ok(PruningMode::ArchiveAll),
+			None => Ok(PruningMode::default()),
+		}
+	}
+
+	/// Get the FROM block tracing receiver.
+	fn tracing_receiver(&self) -> Result<TracingReceiver> {
+		Ok(Default::default())
+	}
+
+	/// Get the tracing targets.
+	fn tracing_targets(&self) -> Result<Option<String>> {
+		Ok(None)
+	}
+
+	/// Get the state cache size.
+	fn state_cache_size(&self) -> Result<usize> {
+		Ok(Default::default())
+	}
+
+	/// Get the WASM execution method.
+	fn wasm_method(&self) -> Result<WasmExecutionMethod> {
+		let file_value = self.shared_params().config_file()?.and_then(|f| f.wasm_method);
+		match ConfigurationFile::resolve(None, file_value) {
+			Some(s) => WasmExecutionMethod::from_str(&s)
+				.map_err(|e| crate::error::Error::Input(format!("Invalid wasm execution method in config file: {}", e))),
+			None => Ok(Default::default()),
+		}
+	}
+
+	/// Get the execution strategies.
+	fn execution_strategies(&self, _is_dev: bool) -> Result<ExecutionStrategies> {
+		let file_value = self.shared_params().config_file()?.and_then(|f| f.execution_strategies);
+		match file_value {
+			Some(overrides) => overrides.merge(ExecutionStrategies::default()),
+			None => Ok(ExecutionStrategies::default()),
+		}
+	}
+
+	/// Get the database cache size.
+	fn database_cache_size(&self) -> Result<Option<usize>> {
+		let cli_value = self.shared_params().database_cache_size;
+		let file_value = self.shared_params().config_file()?.and_then(|f| f.database_cache_size);
+		Ok(ConfigurationFile::resolve(cli_value, file_value))
+	}
+
+	/// Get the node key.
+	fn node_key(&self, net_config_dir: &PathBuf) -> Result<NodeKeyConfig> {
+		let file_value = self.shared_params().config_file()?.and_then(|f| f.node_key);
+		let secret = match ConfigurationFile::resolve(None, file_value) {
+			Some(hex_seed) => Secret::Input(parse_ed25519_secret(&hex_seed)?),
+			None => Secret::File(net_config_dir.join(NODE_KEY_ED25519_FILE)),
+		};
+		Ok(NodeKeyConfig::Ed25519(secret))
+	}
+}
+
+/// Name of the file that stores the node's default Ed25519 network identity.
+const NODE_KEY_ED25519_FILE: &str = "secret_ed25519";
+
+/// Parse a hex-encoded Ed25519 secret key, as accepted by the `node_key` CLI flag and the
+/// `node_key` config file entry.
+fn parse_ed25519_secret(hex: &str) -> Result<ed25519::SecretKey> {
+	array_bytes::hex2bytes(hex)
+		.map_err(|_| crate::error::Error::Input("Invalid node key: not valid hex".into()))
+		.and_then(|bytes| {
+			ed25519::SecretKey::from_bytes(bytes)
+				.map_err(|_| crate::error::Error::Input("Invalid node key: invalid Ed25519 secret".into()))
+		})
+}